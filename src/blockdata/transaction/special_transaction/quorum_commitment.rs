@@ -99,6 +99,32 @@ pub struct QuorumCommitmentPayload {
     finalization_commitment: QuorumFinalizationCommitment,
 }
 
+impl QuorumCommitmentPayload {
+    /// Creates a new quorum commitment payload mined at the given height.
+    pub fn new(version: u16, height: u32, finalization_commitment: QuorumFinalizationCommitment) -> Self {
+        QuorumCommitmentPayload {
+            version,
+            height,
+            finalization_commitment
+        }
+    }
+
+    /// The version of the quorum commitment payload.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// The height of the block the commitment was mined in.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The final commitment of the DKG session carried by this payload.
+    pub fn finalization_commitment(&self) -> &QuorumFinalizationCommitment {
+        &self.finalization_commitment
+    }
+}
+
 impl Encodable for QuorumCommitmentPayload {
     fn consensus_encode<S: Write>(&self, mut s: S) -> Result<usize, Error> {
         let mut len = 0;
@@ -125,5 +151,26 @@ impl Decodable for QuorumCommitmentPayload {
 
 #[cfg(test)]
 mod tests {
+    use consensus::{deserialize, serialize};
+    use blockdata::transaction::special_transaction::quorum_commitment::{QuorumCommitmentPayload, QuorumFinalizationCommitment};
 
+    #[test]
+    fn test_quorum_commitment_payload_accessors() {
+        let mut commitment_bytes = vec![1, 0, 1];
+        commitment_bytes.extend_from_slice(&[0x11; 32]); // quorum hash
+        commitment_bytes.extend_from_slice(&[0, 0]); // signers, valid members
+        commitment_bytes.extend_from_slice(&[0x22; 48]); // quorum public key
+        commitment_bytes.extend_from_slice(&[0x33; 32]); // quorum vvec hash
+        commitment_bytes.extend_from_slice(&[0x44; 96]); // quorum sig
+        commitment_bytes.extend_from_slice(&[0x55; 96]); // sig
+        let commitment: QuorumFinalizationCommitment = deserialize(&commitment_bytes).unwrap();
+
+        let payload = QuorumCommitmentPayload::new(1, 850_000, commitment.clone());
+        assert_eq!(payload.version(), 1);
+        assert_eq!(payload.height(), 850_000);
+        assert_eq!(payload.finalization_commitment(), &commitment);
+
+        let decoded: QuorumCommitmentPayload = deserialize(&serialize(&payload)).unwrap();
+        assert_eq!(decoded, payload);
+    }
 }
\ No newline at end of file
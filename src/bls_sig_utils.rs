@@ -18,6 +18,7 @@
 //!
 
 use core::{fmt};
+use hashes;
 
 impl_array_newtype!(BLSPublicKey, u8, 48);
 impl_bytes_newtype!(BLSPublicKey, 48);
@@ -54,6 +55,36 @@ macro_rules! impl_elementencode {
 }
 
 
+macro_rules! impl_from_bytes {
+    ($element:ident, $len:expr) => {
+        impl $element {
+            /// Creates the element from a byte slice, failing if the slice doesn't have
+            /// the exact length of the element instead of panicking like `From<&[u8]>`.
+            pub fn from_bytes(bytes: &[u8]) -> Result<$element, hashes::Error> {
+                if bytes.len() != $len {
+                    return Err(hashes::Error::InvalidLength($len, bytes.len()));
+                }
+                let mut data :[u8;$len] = [0u8; $len];
+                data.copy_from_slice(bytes);
+                Ok($element(data))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+macro_rules! impl_ct_eq {
+    ($element:ident) => {
+        /// Constant-time comparison of the underlying bytes. Note that the derived `PartialEq`,
+        /// `Ord` and `Hash` implementations are not constant-time.
+        #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+        impl ::subtle::ConstantTimeEq for $element {
+            fn ct_eq(&self, other: &Self) -> ::subtle::Choice {
+                self.0[..].ct_eq(&other.0[..])
+            }
+        }
+    }
+}
 
 #[rustversion::before(1.48)]
 macro_rules! impl_eq_ord_hash {
@@ -108,3 +139,57 @@ impl_eq_ord_hash!(BLSSignature, 96);
 
 impl_elementencode!(BLSPublicKey, 48);
 impl_elementencode!(BLSSignature, 96);
+
+impl_from_bytes!(BLSPublicKey, 48);
+impl_from_bytes!(BLSSignature, 96);
+
+#[cfg(feature = "subtle")]
+impl_ct_eq!(BLSPublicKey);
+#[cfg(feature = "subtle")]
+impl_ct_eq!(BLSSignature);
+
+#[cfg(test)]
+mod tests {
+    use hashes;
+    use hashes::hex::FromHex;
+    use bls_sig_utils::{BLSPublicKey, BLSSignature};
+
+    #[test]
+    fn test_from_bytes_checks_length() {
+        assert_eq!(BLSPublicKey::from_bytes(&[1u8; 48]).unwrap(), BLSPublicKey::from(&[1u8; 48][..]));
+        assert_eq!(BLSPublicKey::from_bytes(&[1u8; 47]), Err(hashes::Error::InvalidLength(48, 47)));
+        assert_eq!(BLSPublicKey::from_bytes(&[1u8; 49]), Err(hashes::Error::InvalidLength(48, 49)));
+
+        assert_eq!(BLSSignature::from_bytes(&[2u8; 96]).unwrap(), BLSSignature::from(&[2u8; 96][..]));
+        assert_eq!(BLSSignature::from_bytes(&[2u8; 95]), Err(hashes::Error::InvalidLength(96, 95)));
+        assert_eq!(BLSSignature::from_bytes(&[2u8; 97]), Err(hashes::Error::InvalidLength(96, 97)));
+    }
+
+    #[test]
+    #[cfg(feature = "subtle")]
+    fn test_ct_eq_agrees_with_eq() {
+        use subtle::ConstantTimeEq;
+
+        let key = BLSPublicKey::from_bytes(&[1u8; 48]).unwrap();
+        let mut other_bytes = [1u8; 48];
+        other_bytes[47] = 2;
+        let other_key = BLSPublicKey::from_bytes(&other_bytes).unwrap();
+        assert_eq!(bool::from(key.ct_eq(&key.clone())), key == key.clone());
+        assert_eq!(bool::from(key.ct_eq(&other_key)), key == other_key);
+        assert!(!bool::from(key.ct_eq(&other_key)));
+
+        let sig = BLSSignature::from_bytes(&[3u8; 96]).unwrap();
+        let other_sig = BLSSignature::from_bytes(&[4u8; 96]).unwrap();
+        assert!(bool::from(sig.ct_eq(&sig.clone())));
+        assert!(!bool::from(sig.ct_eq(&other_sig)));
+    }
+
+    #[test]
+    fn test_from_hex_checks_length() {
+        assert!(BLSPublicKey::from_hex(&"01".repeat(48)).is_ok());
+        assert!(BLSPublicKey::from_hex(&"01".repeat(47)).is_err());
+        assert!(BLSPublicKey::from_hex(&"01".repeat(49)).is_err());
+        assert!(BLSSignature::from_hex(&"02".repeat(95)).is_err());
+        assert!(BLSSignature::from_hex(&"02".repeat(97)).is_err());
+    }
+}
@@ -3,8 +3,10 @@
 //! represents a p2p message containing a data to verify such a lock.
 
 use consensus::{Decodable, Encodable, encode};
-use consensus::encode::MAX_VEC_SIZE;
+use consensus::encode::{MAX_VEC_SIZE, VarInt};
 use ::{io, Txid};
+use hashes::{Hash, sha256d};
+use prelude::Cow;
 use core::fmt::{Debug, Formatter};
 #[cfg(all(not(feature = "std"), not(test)))]
 use alloc::vec::Vec;
@@ -18,6 +20,9 @@ type BlsSignature = [u8; 96];
 
 type CycleHash = [u8; 32];
 
+/// Prefix used by Dash Core when hashing the inputs of an instant send lock into its request id
+const IS_LOCK_REQUEST_ID_PREFIX: &str = "islock";
+
 #[derive(Clone, Eq, PartialEq)]
 // #[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 /// Instant send lock is a mechanism used by the Dash network to
@@ -50,6 +55,27 @@ impl Default for InstantLock {
     }
 }
 
+impl InstantLock {
+    /// Computes the request id of this lock, which is used to select the quorum
+    /// responsible for signing it.
+    pub fn request_id(&self) -> sha256d::Hash {
+        Self::request_id_for_inputs(&self.inputs)
+    }
+
+    /// Computes the instant send lock request id for the given transaction inputs:
+    /// the double SHA256 of the `"islock"` prefix (as a length-prefixed string) followed
+    /// by the length-prefixed list of outpoints, as done by Dash Core.
+    pub fn request_id_for_inputs(inputs: &[OutPoint]) -> sha256d::Hash {
+        let mut engine = sha256d::Hash::engine();
+        Cow::from(IS_LOCK_REQUEST_ID_PREFIX).consensus_encode(&mut engine).expect("engines don't error");
+        VarInt(inputs.len() as u64).consensus_encode(&mut engine).expect("engines don't error");
+        for input in inputs {
+            input.consensus_encode(&mut engine).expect("engines don't error");
+        }
+        sha256d::Hash::from_engine(engine)
+    }
+}
+
 impl Debug for InstantLock {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         formatter.debug_struct("InstantLock")
@@ -113,10 +139,12 @@ mod is_lock_test {
         let vec = Vec::from_hex(hex).unwrap();
 
         // let expected_hash = "4ee6a4ed2b6c70efd401c6c91dfaf6c61badd13f80ec07c281bb93d5270fcd58";
-        // let expected_request_id = "495be44677e82895a9396fef02c6e9afc1f01d4aff70622b9f78e0e10d57064c";
+        let expected_request_id = "495be44677e82895a9396fef02c6e9afc1f01d4aff70622b9f78e0e10d57064c";
         
         let is_lock: InstantLock = deserialize(&vec).unwrap();
         assert_eq!(is_lock.version, 1);
+        assert_eq!(is_lock.request_id().to_hex(), expected_request_id);
+        assert_eq!(InstantLock::request_id_for_inputs(&is_lock.inputs), is_lock.request_id());
         
         // TODO: check outpoints
 
@@ -119,6 +119,71 @@ impl Network {
             Network::Regtest => 0xDAB5BFFA,
         }
     }
+
+    /// Returns the block height at which the Core v19 hard fork activates.
+    ///
+    /// Devnets and regtest have configurable activation heights, so the
+    /// feature is considered active from genesis on them.
+    pub fn core_v19_activation_height(self) -> u32 {
+        match self {
+            Network::Dash => 1899072,
+            Network::Testnet => 850100,
+            Network::Devnet | Network::Regtest => 0,
+        }
+    }
+
+    /// Returns the block height at which the Core v20 hard fork activates.
+    ///
+    /// Devnets and regtest have configurable activation heights, so the
+    /// feature is considered active from genesis on them.
+    pub fn core_v20_activation_height(self) -> u32 {
+        match self {
+            Network::Dash => 1987776,
+            Network::Testnet => 905100,
+            Network::Devnet | Network::Regtest => 0,
+        }
+    }
+
+    /// Returns the block height at which the given feature activates.
+    pub fn activation_height(self, feature: CoreFeature) -> u32 {
+        match feature {
+            CoreFeature::CoreV19 => self.core_v19_activation_height(),
+            CoreFeature::CoreV20 => self.core_v20_activation_height(),
+        }
+    }
+
+    /// Checks whether the given feature is active at the given block height.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dashcore::network::constants::{CoreFeature, Network};
+    ///
+    /// assert!(!Network::Dash.is_feature_active_at(CoreFeature::CoreV20, 1987775));
+    /// assert!(Network::Dash.is_feature_active_at(CoreFeature::CoreV20, 1987776));
+    /// ```
+    pub fn is_feature_active_at(self, feature: CoreFeature, height: u32) -> bool {
+        height >= self.activation_height(feature)
+    }
+
+    /// Checks whether Core v19 is active at the given block height.
+    pub fn core_v19_is_active_at(self, height: u32) -> bool {
+        self.is_feature_active_at(CoreFeature::CoreV19, height)
+    }
+
+    /// Checks whether Core v20 is active at the given block height.
+    pub fn core_v20_is_active_at(self, height: u32) -> bool {
+        self.is_feature_active_at(CoreFeature::CoreV20, height)
+    }
+}
+
+/// Dash Core hard forks whose activation depends on the block height.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum CoreFeature {
+    /// Core v19, which switches BLS keys and signatures from the legacy to the basic scheme
+    CoreV19,
+    /// Core v20, which derives quorum modifiers from the best chain lock signature
+    CoreV20,
 }
 
 /// Flags to indicate which network services a node supports.
@@ -157,6 +222,11 @@ impl ServiceFlags {
     /// See BIP159 for details on how this is implemented.
     pub const NETWORK_LIMITED: ServiceFlags = ServiceFlags(1 << 10);
 
+    /// HEADERS_COMPRESSED means the node is capable of serving compressed block headers
+    /// through the `getheaders2`/`headers2` messages.
+    /// See DIP25 for details on how this is implemented.
+    pub const HEADERS_COMPRESSED: ServiceFlags = ServiceFlags(1 << 11);
+
     // NOTE: When adding new flags, remember to update the Display impl accordingly.
 
     /// Add [ServiceFlags] together.
@@ -224,6 +294,7 @@ impl fmt::Display for ServiceFlags {
         write_flag!(WITNESS);
         write_flag!(COMPACT_FILTERS);
         write_flag!(NETWORK_LIMITED);
+        write_flag!(HEADERS_COMPRESSED);
         // If there are unknown flags left, we append them in hex.
         if flags != ServiceFlags::NONE {
             if !first {
@@ -291,7 +362,7 @@ impl Decodable for ServiceFlags {
 
 #[cfg(test)]
 mod tests {
-    use super::{Network, ServiceFlags};
+    use super::{CoreFeature, Network, ServiceFlags};
     use consensus::encode::{deserialize, serialize};
 
     #[test]
@@ -322,6 +393,19 @@ mod tests {
         assert!("fakenet".parse::<Network>().is_err());
     }
 
+    #[test]
+    fn feature_activation_test() {
+        assert!(!Network::Dash.core_v19_is_active_at(1899071));
+        assert!(Network::Dash.core_v19_is_active_at(1899072));
+        assert!(!Network::Testnet.core_v20_is_active_at(905099));
+        assert!(Network::Testnet.core_v20_is_active_at(905100));
+        assert!(Network::Regtest.core_v20_is_active_at(0));
+        assert!(Network::Devnet.is_feature_active_at(CoreFeature::CoreV19, 0));
+
+        assert_eq!(Network::Dash.activation_height(CoreFeature::CoreV19), Network::Dash.core_v19_activation_height());
+        assert_eq!(Network::Dash.activation_height(CoreFeature::CoreV20), Network::Dash.core_v20_activation_height());
+    }
+
     #[test]
     fn service_flags_test() {
         let all = [
@@ -331,6 +415,7 @@ mod tests {
             ServiceFlags::WITNESS,
             ServiceFlags::COMPACT_FILTERS,
             ServiceFlags::NETWORK_LIMITED,
+            ServiceFlags::HEADERS_COMPRESSED,
         ];
 
         let mut flags = ServiceFlags::NONE;
@@ -360,5 +445,10 @@ mod tests {
         assert_eq!("ServiceFlags(NETWORK|BLOOM|WITNESS)", flag.to_string());
         let flag = ServiceFlags::WITNESS | 0xf0.into();
         assert_eq!("ServiceFlags(WITNESS|COMPACT_FILTERS|0xb0)", flag.to_string());
+        let flag = ServiceFlags::NETWORK | ServiceFlags::BLOOM | ServiceFlags::HEADERS_COMPRESSED;
+        assert!(flag.has(ServiceFlags::HEADERS_COMPRESSED));
+        assert!(!flag.has(ServiceFlags::NETWORK_LIMITED));
+        assert_eq!(flag.as_u64(), 0x805);
+        assert_eq!("ServiceFlags(NETWORK|BLOOM|HEADERS_COMPRESSED)", flag.to_string());
     }
 }
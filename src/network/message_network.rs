@@ -108,6 +108,22 @@ pub enum RejectReason {
     Checkpoint = 0x43
 }
 
+impl RejectReason {
+    /// Returns a short name of the rejection reason, suitable for logging
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RejectReason::Malformed => "malformed",
+            RejectReason::Invalid => "invalid",
+            RejectReason::Obsolete => "obsolete",
+            RejectReason::Duplicate => "duplicate",
+            RejectReason::NonStandard => "nonstandard",
+            RejectReason::Dust => "dust",
+            RejectReason::Fee => "insufficientfee",
+            RejectReason::Checkpoint => "checkpoint",
+        }
+    }
+}
+
 impl Encodable for RejectReason {
     fn consensus_encode<W: io::Write>(&self, mut e: W) -> Result<usize, io::Error> {
         e.write_all(&[*self as u8])?;
@@ -144,6 +160,23 @@ pub struct Reject {
     pub hash: sha256d::Hash
 }
 
+impl Reject {
+    /// Constructs a new `reject` message
+    pub fn new(
+        message: impl Into<Cow<'static, str>>,
+        ccode: RejectReason,
+        reason: impl Into<Cow<'static, str>>,
+        hash: sha256d::Hash,
+    ) -> Reject {
+        Reject {
+            message: message.into(),
+            ccode,
+            reason: reason.into(),
+            hash,
+        }
+    }
+}
+
 impl_consensus_encoding!(Reject, message, ccode, reason, hash);
 
 #[cfg(test)]
@@ -209,5 +242,10 @@ mod tests {
 
         assert_eq!(serialize(&conflict), reject_tx_conflict);
         assert_eq!(serialize(&nonfinal), reject_tx_nonfinal);
+
+        let constructed = Reject::new("tx", RejectReason::Duplicate, String::from("txn-mempool-conflict"), conflict.hash);
+        assert_eq!(constructed, conflict);
+        assert_eq!(serialize(&constructed), reject_tx_conflict);
+        assert_eq!(RejectReason::NonStandard.as_str(), "nonstandard");
     }
 }